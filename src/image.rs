@@ -0,0 +1,226 @@
+//! Structured OCI image references, so manifest consumers can pin and verify
+//! component images instead of trusting mutable tags.
+
+use sha2::Digest;
+use sha2::Sha256;
+
+use crate::CapabilityComponent;
+
+const DEFAULT_REGISTRY: &str = "docker.io";
+const DEFAULT_TAG: &str = "latest";
+
+/// A parsed `registry/repository:tag[@sha256:<hex>]` OCI image reference.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageRef {
+    pub registry: String,
+    pub repository: String,
+    pub tag: String,
+    pub digest: Option<String>,
+}
+
+/// Errors that can occur while parsing an [`ImageRef`].
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ImageRefError {
+    /// The component has no `properties`, so there's no image to parse.
+    #[error("component has no properties to read an image from")]
+    MissingProperties,
+    /// The image string isn't a well-formed OCI reference.
+    #[error("{0:?} is not a valid OCI image reference")]
+    Invalid(String),
+    /// The `@sha256:...` suffix isn't a valid digest.
+    #[error("{0:?} is not a valid sha256 digest")]
+    InvalidDigest(String),
+}
+
+impl ImageRef {
+    /// Parses `ghcr.io/jabratech/ticker-provider:0.1.0` (or with an `@sha256:...`
+    /// suffix) into its component parts, applying the same implicit defaults
+    /// `docker` does: no registry means `docker.io`, no tag means `latest`.
+    pub fn parse(image: &str) -> Result<Self, ImageRefError> {
+        if image.is_empty() || image.chars().any(char::is_whitespace) {
+            return Err(ImageRefError::Invalid(image.to_string()));
+        }
+
+        let (rest, digest) = match image.split_once('@') {
+            Some((rest, digest)) => (rest, Some(parse_digest(digest)?)),
+            None => (image, None),
+        };
+
+        // A tag's `:` only ever appears after the last `/`, i.e. within the
+        // repository's final path segment. Looking for it anywhere in `rest`
+        // would misread a `host:port` registry with no explicit tag (e.g.
+        // `localhost:5000/myrepo`) as a malformed tag.
+        let last_segment_start = rest.rfind('/').map_or(0, |i| i + 1);
+        let (repo_and_registry, tag) = match rest[last_segment_start..].rfind(':') {
+            Some(i) => {
+                let tag = &rest[last_segment_start + i + 1..];
+                if tag.is_empty() {
+                    return Err(ImageRefError::Invalid(image.to_string()));
+                }
+                (&rest[..last_segment_start + i], tag.to_string())
+            }
+            None => (rest, DEFAULT_TAG.to_string()),
+        };
+
+        if repo_and_registry.is_empty() {
+            return Err(ImageRefError::Invalid(image.to_string()));
+        }
+
+        let (first, remainder) = match repo_and_registry.split_once('/') {
+            Some((first, remainder)) => (first, Some(remainder)),
+            None => (repo_and_registry, None),
+        };
+
+        let (registry, repository) = match remainder {
+            Some(repository) if first.contains('.') || first.contains(':') || first == "localhost" => {
+                (first.to_string(), repository.to_string())
+            }
+            Some(repository) => (DEFAULT_REGISTRY.to_string(), format!("{first}/{repository}")),
+            None => (DEFAULT_REGISTRY.to_string(), first.to_string()),
+        };
+
+        if repository.is_empty() {
+            return Err(ImageRefError::Invalid(image.to_string()));
+        }
+
+        Ok(ImageRef {
+            registry,
+            repository,
+            tag,
+            digest,
+        })
+    }
+
+    /// Computes the sha256 digest of `bytes` and compares it against the
+    /// pinned digest, if any. Returns `false` when no digest is pinned, since
+    /// there's nothing to verify against.
+    pub fn verify_digest(&self, bytes: &[u8]) -> bool {
+        let Some(digest) = &self.digest else {
+            return false;
+        };
+
+        let mut hasher = Sha256::new();
+        hasher.update(bytes);
+        let computed = format!("sha256:{:x}", hasher.finalize());
+        computed == *digest
+    }
+}
+
+fn parse_digest(digest: &str) -> Result<String, ImageRefError> {
+    let hex = digest
+        .strip_prefix("sha256:")
+        .ok_or_else(|| ImageRefError::InvalidDigest(digest.to_string()))?;
+    if hex.len() != 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ImageRefError::InvalidDigest(digest.to_string()));
+    }
+
+    Ok(digest.to_string())
+}
+
+impl CapabilityComponent {
+    /// Parses this component's `properties.image` into a structured [`ImageRef`].
+    pub fn image_ref(&self) -> Result<ImageRef, ImageRefError> {
+        let properties = self
+            .properties
+            .as_ref()
+            .ok_or(ImageRefError::MissingProperties)?;
+        ImageRef::parse(&properties.image)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_reference_with_digest() {
+        let image_ref = ImageRef::parse(
+            "ghcr.io/jabratech/ticker-provider:0.1.0@sha256:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+        )
+        .unwrap();
+
+        assert_eq!(
+            image_ref,
+            ImageRef {
+                registry: "ghcr.io".to_string(),
+                repository: "jabratech/ticker-provider".to_string(),
+                tag: "0.1.0".to_string(),
+                digest: Some(
+                    "sha256:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"
+                        .to_string()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_defaults_registry_and_tag() {
+        let image_ref = ImageRef::parse("jabratech/ticker-provider").unwrap();
+
+        assert_eq!(image_ref.registry, "docker.io");
+        assert_eq!(image_ref.repository, "jabratech/ticker-provider");
+        assert_eq!(image_ref.tag, "latest");
+        assert_eq!(image_ref.digest, None);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_digest() {
+        let err = ImageRef::parse("jabratech/ticker-provider@sha256:not-hex").unwrap_err();
+        assert!(matches!(err, ImageRefError::InvalidDigest(_)));
+    }
+
+    #[test]
+    fn test_parse_port_registry_without_tag_defaults_to_latest() {
+        let image_ref = ImageRef::parse("localhost:5000/myrepo").unwrap();
+
+        assert_eq!(image_ref.registry, "localhost:5000");
+        assert_eq!(image_ref.repository, "myrepo");
+        assert_eq!(image_ref.tag, "latest");
+        assert_eq!(image_ref.digest, None);
+    }
+
+    #[test]
+    fn test_parse_port_registry_with_tag() {
+        let image_ref = ImageRef::parse("registry.example.com:5000/repo:1.0").unwrap();
+
+        assert_eq!(image_ref.registry, "registry.example.com:5000");
+        assert_eq!(image_ref.repository, "repo");
+        assert_eq!(image_ref.tag, "1.0");
+        assert_eq!(image_ref.digest, None);
+    }
+
+    #[test]
+    fn test_parse_rejects_empty_repository() {
+        let err = ImageRef::parse(":0.1.0").unwrap_err();
+        assert!(matches!(err, ImageRefError::Invalid(_)));
+    }
+
+    #[test]
+    fn test_verify_digest_matches_computed_hash() {
+        let mut hasher = Sha256::new();
+        hasher.update(b"hello");
+        let digest = format!("sha256:{:x}", hasher.finalize());
+
+        let image_ref = ImageRef {
+            registry: "docker.io".to_string(),
+            repository: "jabratech/ticker-provider".to_string(),
+            tag: "latest".to_string(),
+            digest: Some(digest),
+        };
+
+        assert!(image_ref.verify_digest(b"hello"));
+        assert!(!image_ref.verify_digest(b"goodbye"));
+    }
+
+    #[test]
+    fn test_verify_digest_without_pinned_digest_is_false() {
+        let image_ref = ImageRef {
+            registry: "docker.io".to_string(),
+            repository: "jabratech/ticker-provider".to_string(),
+            tag: "latest".to_string(),
+            digest: None,
+        };
+
+        assert!(!image_ref.verify_digest(b"hello"));
+    }
+}
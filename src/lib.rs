@@ -1,12 +1,30 @@
+use std::collections::BTreeMap;
 use std::error::Error;
 
+use semver::Version;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Error as SerdeError;
 use serde_with::serde_as;
 
+mod image;
+mod loader;
+mod one_or_many;
+mod validate;
+
+pub use image::ImageRef;
+pub use image::ImageRefError;
+pub use loader::load_all;
+pub use loader::load_manifest;
+pub use validate::validate;
+pub use validate::Diagnostic;
+
+use one_or_many::deserialize_one_or_many;
+
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct ConfigProperties {
     pub uri: Option<String>,
     pub exchange_name: Option<String>,
@@ -17,6 +35,8 @@ pub struct ConfigProperties {
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Config {
     pub name: String,
     pub properties: Option<ConfigProperties>,
@@ -24,17 +44,23 @@ pub struct Config {
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct CapabilityComponent {
     pub name: String,
     #[serde(rename = "type")]
+    #[cfg_attr(feature = "ts", ts(rename = "type"))]
     pub component_type: String,
     pub properties: Option<Properties>,
 }
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Properties {
     pub image: String,
+    #[serde(default, deserialize_with = "deserialize_one_or_many")]
     pub config: Option<Vec<Config>>,
 }
 
@@ -46,8 +72,11 @@ impl AsRef<Properties> for Properties {
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Manifest {
     #[serde(rename = "apiVersion")]
+    #[cfg_attr(feature = "ts", ts(rename = "apiVersion"))]
     pub api_version: String,
     pub kind: String,
     pub metadata: Metadata,
@@ -56,6 +85,8 @@ pub struct Manifest {
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Metadata {
     pub name: String,
     pub annotations: Annotations,
@@ -63,6 +94,8 @@ pub struct Metadata {
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Annotations {
     pub description: String,
     pub version: String,
@@ -70,6 +103,8 @@ pub struct Annotations {
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Spec {
     pub components: Vec<CapabilityComponent>,
 }
@@ -77,23 +112,124 @@ pub struct Spec {
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 pub struct Manifests {
-    #[serde(rename = "v0.0.1")]
-    pub version: Manifest,
+    #[serde(flatten)]
+    pub versions: BTreeMap<Version, Manifest>,
 }
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "ts", derive(ts_rs::TS))]
+#[cfg_attr(feature = "ts", ts(export))]
 pub struct Root {
+    #[cfg_attr(feature = "ts", ts(type = "Record<string, Manifest>"))]
     pub manifests: Manifests,
     pub deployed_version: Option<String>,
 }
 
+/// Errors that can occur while reading or selecting manifests.
+#[derive(Debug, thiserror::Error)]
+pub enum ManifestError {
+    /// The `manifests` map did not contain any versions to select from, or
+    /// (from [`Root::deployed`]) `deployed_version` is unset.
+    #[error("manifest has no versions")]
+    NoVersions,
+    /// `deployed_version` did not parse as a valid semver version.
+    #[error("invalid manifest version {0:?}: {1}")]
+    InvalidVersion(String, semver::Error),
+    /// The requested version was not present in the `manifests` map.
+    #[error("manifest version {0:?} not found")]
+    VersionNotFound(String),
+    /// The underlying JSON payload failed to parse, or a value failed to
+    /// serialize to JSON.
+    #[error("failed to parse manifest: {0}")]
+    Parse(SerdeError),
+    /// The requested app was not present in the app-name list.
+    #[error("app {0:?} not found")]
+    AppNotFound(String),
+    /// The app-name list itself was missing from the KV store.
+    #[error("app name list not found")]
+    AppListMissing,
+    /// The app-name list was present but failed to parse as JSON.
+    #[error("failed to parse app name list: {0}")]
+    AppListParse(SerdeError),
+    /// The app-specific manifest config was missing from the KV store.
+    #[error("config for app {0:?} not found")]
+    ConfigNotFound(String),
+    /// The config bytes retrieved for an app were not valid UTF-8.
+    #[error("config is not valid UTF-8: {0}")]
+    Utf8(#[from] std::string::FromUtf8Error),
+    /// The underlying KV store backend failed.
+    #[error("KV backend error: {0}")]
+    Backend(#[source] Box<dyn Error + Send + Sync>),
+    /// A manifest file could not be read from disk.
+    #[error("failed to read manifest file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A manifest file's extension didn't match a supported format.
+    #[error("{0:?} has an unsupported manifest file extension")]
+    UnknownFormat(String),
+    /// A `.flex.bin` manifest file failed to decode as FlexBuffers.
+    #[error("failed to parse FlexBuffers manifest: {0}")]
+    FlexBuffers(String),
+}
+
+impl Root {
+    /// Returns the [`Manifest`] for the highest-sorted version in `manifests`.
+    pub fn latest(&self) -> Result<&Manifest, ManifestError> {
+        self.manifests
+            .versions
+            .iter()
+            .next_back()
+            .map(|(_, manifest)| manifest)
+            .ok_or(ManifestError::NoVersions)
+    }
+
+    /// Returns the [`Manifest`] matching `deployed_version`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ManifestError::NoVersions`] if `deployed_version` is unset,
+    /// [`ManifestError::InvalidVersion`] if it isn't a valid semver string, and
+    /// [`ManifestError::VersionNotFound`] if it doesn't match a key in `manifests`.
+    pub fn deployed(&self) -> Result<&Manifest, ManifestError> {
+        let deployed_version = self
+            .deployed_version
+            .as_deref()
+            .ok_or(ManifestError::NoVersions)?;
+        let version = Version::parse(deployed_version)
+            .map_err(|e| ManifestError::InvalidVersion(deployed_version.to_string(), e))?;
+        self.manifests
+            .versions
+            .get(&version)
+            .ok_or_else(|| ManifestError::VersionNotFound(deployed_version.to_string()))
+    }
+}
+
+fn filter_capability_components(manifest: &Manifest) -> Vec<CapabilityComponent> {
+    manifest
+        .spec
+        .components
+        .iter()
+        .filter(|comp| {
+            comp.component_type == "capability"
+                && comp
+                    .properties
+                    .as_ref()
+                    .map_or(false, |p| p.config.is_some())
+        })
+        .cloned()
+        .collect()
+}
+
 /// Extracts capability components from the given JSON configuration string.
 ///
 /// This function parses the provided JSON string representing a configuration,
 /// filters the components to find those of type `"capability"` that have a
 /// `config`, and returns a vector of these capability components.
 ///
+/// The manifest version used is the deployed version if `deployed_version` is
+/// set, falling back to the latest version otherwise. Use
+/// [`extract_capability_components_for_version`] to select an explicit version.
+///
 /// # Arguments
 ///
 /// * `config` - A string slice that holds the JSON configuration.
@@ -102,30 +238,39 @@ pub struct Root {
 ///
 /// A vector of `CapabilityComponent` structs that match the criteria of being
 /// of type `"capability"` and having a `config`.
+pub fn extract_capability_components(
+    config: &str,
+) -> Result<Vec<CapabilityComponent>, ManifestError> {
+    extract_capability_components_for_version(config, None)
+}
+
+/// Like [`extract_capability_components`], but selects an explicit manifest
+/// `version` instead of defaulting to the deployed (or latest) version.
 ///
-/// # Panics
-///
-/// This function will panic if the JSON string cannot be parsed into the
-/// expected structure.
-pub fn extract_capability_components(config: &str) -> Result<Vec<CapabilityComponent>, SerdeError> {
-    let parsed = serde_json::from_str::<Root>(config)?;
-    let components = &parsed.manifests.version.spec.components;
-    let capability_components: Vec<CapabilityComponent> = components
-        .iter()
-        .filter(|comp| {
-            comp.component_type == "capability"
-                && comp
-                    .properties
-                    .as_ref()
-                    .map_or(false, |p| p.config.is_some())
-        })
-        .cloned()
-        .collect();
+/// Passing `None` for `version` falls back to the deployed version when
+/// `deployed_version` is set, and to the latest version otherwise.
+pub fn extract_capability_components_for_version(
+    config: &str,
+    version: Option<&Version>,
+) -> Result<Vec<CapabilityComponent>, ManifestError> {
+    let parsed = serde_json::from_str::<Root>(config).map_err(ManifestError::Parse)?;
+    let manifest = match version {
+        Some(version) => parsed
+            .manifests
+            .versions
+            .get(version)
+            .ok_or_else(|| ManifestError::VersionNotFound(version.to_string()))?,
+        None => match parsed.deployed() {
+            Ok(manifest) => manifest,
+            Err(ManifestError::NoVersions) => parsed.latest()?,
+            Err(e) => return Err(e),
+        },
+    };
 
-    Ok(capability_components)
+    Ok(filter_capability_components(manifest))
 }
 
-pub type GetFn = fn(&str, &str) -> Result<Option<Vec<u8>>, Box<dyn Error>>;
+pub type GetFn = fn(&str, &str) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync>>;
 
 /// Fetches the manifest configuration for a specific application.
 ///
@@ -136,7 +281,7 @@ pub type GetFn = fn(&str, &str) -> Result<Option<Vec<u8>>, Box<dyn Error>>;
 ///
 /// # Arguments
 ///
-/// * `get_fn` - A generic function that follows the signature `fn(&str, &str) -> Result<Option<Vec<u8>>, Box<dyn Error>>`.
+/// * `get_fn` - A generic function that follows the signature `fn(&str, &str) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync>>`.
 ///              This function is responsible for retrieving data from a key-value store.
 /// * `app_name` - The name of the application to retrieve the configuration for.
 /// * `wadm_manifest` - The name of the bucket containing the application manifests.
@@ -144,61 +289,152 @@ pub type GetFn = fn(&str, &str) -> Result<Option<Vec<u8>>, Box<dyn Error>>;
 ///
 /// # Errors
 ///
-/// This function will return an error if:
-/// - There is a failure in retrieving the list of applications.
-/// - The application name is not found in the list.
+/// This function will return a [`ManifestError`] if:
+/// - There is a failure in retrieving the list of applications ([`ManifestError::Backend`],
+///   [`ManifestError::AppListMissing`], [`ManifestError::AppListParse`]).
+/// - The application name is not found in the list ([`ManifestError::AppNotFound`]).
 /// - There is a failure in retrieving the configuration for the found
-///   application.
-/// - There is an issue converting the configuration bytes into a `String`.
+///   application ([`ManifestError::Backend`], [`ManifestError::ConfigNotFound`]).
+/// - There is an issue converting the configuration bytes into a `String`
+///   ([`ManifestError::Utf8`]).
 ///
 /// # Returns
 ///
 /// A `Result` containing:
 /// - `Ok(String)` with the configuration as a `String` if successful.
-/// - `Err(Box<dyn Error>)` if any step of the process fails.
+/// - `Err(ManifestError)` if any step of the process fails.
 pub fn get_manifests(
     get_fn: GetFn,
     app_name: &str,
     wadm_manifest: &str,
     wadm_default_manifest: &str,
-) -> Result<String, Box<dyn Error>> {
-    let apps = match get_fn(wadm_manifest, wadm_default_manifest) {
-        Ok(app_name) => match app_name {
-            Some(apps) => match serde_json::from_slice::<Vec<String>>(&apps) {
-                Ok(apps) => apps,
-                Err(e) => Err(format!(
-                    "Failed to parse app names from default manifest: {e}"
-                ))?,
-            },
-            None => Err("Failed to get app name from default manifest")?,
-        },
-        Err(e) => Err(e)?,
-    };
+) -> Result<String, ManifestError> {
+    let apps = get_fn(wadm_manifest, wadm_default_manifest)
+        .map_err(ManifestError::Backend)?
+        .ok_or(ManifestError::AppListMissing)?;
+    let apps = serde_json::from_slice::<Vec<String>>(&apps).map_err(ManifestError::AppListParse)?;
 
-    let app = match apps.iter().find(|&app| app == app_name) {
-        Some(app) => app,
-        None => Err(format!("App {app_name} not found"))?,
-    };
+    let app = apps
+        .iter()
+        .find(|&app| app == app_name)
+        .ok_or_else(|| ManifestError::AppNotFound(app_name.to_string()))?;
 
     let app_key = format!("{}-{}", wadm_default_manifest, app);
-    let config = get_fn(wadm_manifest, &app_key);
-    match config {
-        Ok(config) => match config {
-            Some(config) => Ok(String::from_utf8(config)?),
-            None => Err("Failed to get config for app: Config not found")?,
-        },
-        Err(e) => Err(format!("Failed to get config for app: {e}"))?,
+    let config = get_fn(wadm_manifest, &app_key)
+        .map_err(ManifestError::Backend)?
+        .ok_or_else(|| ManifestError::ConfigNotFound(app_name.to_string()))?;
+
+    Ok(String::from_utf8(config)?)
+}
+
+pub type PutFn = fn(&str, &str, &[u8]) -> Result<(), Box<dyn Error + Send + Sync>>;
+
+/// Adds `app_name` to the app-name list if it isn't already present, then
+/// writes `manifest` to the KV store under `{wadm_default_manifest}-{app_name}`.
+///
+/// The app-name list is updated first so that a failure partway through never
+/// leaves a manifest blob written but unreachable from [`get_manifests`]: this
+/// is check-then-put, not a single atomic operation, so a racing writer for
+/// the same `app_name` can still interleave with the list update.
+///
+/// # Errors
+///
+/// Returns a [`ManifestError`] if `manifest` fails to serialize
+/// ([`ManifestError::Parse`]), the app-name list fails to parse
+/// ([`ManifestError::AppListParse`]), or the underlying KV store fails
+/// ([`ManifestError::Backend`]).
+pub fn put_manifests(
+    put_fn: PutFn,
+    get_fn: GetFn,
+    app_name: &str,
+    wadm_manifest: &str,
+    wadm_default_manifest: &str,
+    manifest: &Root,
+) -> Result<(), ManifestError> {
+    let mut apps = match get_fn(wadm_manifest, wadm_default_manifest).map_err(ManifestError::Backend)? {
+        Some(apps) => serde_json::from_slice::<Vec<String>>(&apps).map_err(ManifestError::AppListParse)?,
+        None => Vec::new(),
+    };
+
+    if !apps.iter().any(|app| app == app_name) {
+        apps.push(app_name.to_string());
+        let apps_payload = serde_json::to_vec(&apps).map_err(ManifestError::Parse)?;
+        put_fn(wadm_manifest, wadm_default_manifest, &apps_payload).map_err(ManifestError::Backend)?;
+    }
+
+    let app_key = format!("{wadm_default_manifest}-{app_name}");
+    let payload = serde_json::to_vec(manifest).map_err(ManifestError::Parse)?;
+    put_fn(wadm_manifest, &app_key, &payload).map_err(ManifestError::Backend)?;
+
+    Ok(())
+}
+
+/// Reads the current manifest for `app_name`, sets its `deployed_version` to
+/// `version`, and writes it back through [`put_manifests`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`get_manifests`] and [`put_manifests`].
+pub fn set_deployed_version(
+    put_fn: PutFn,
+    get_fn: GetFn,
+    app_name: &str,
+    wadm_manifest: &str,
+    wadm_default_manifest: &str,
+    version: &str,
+) -> Result<(), ManifestError> {
+    let current = get_manifests(get_fn, app_name, wadm_manifest, wadm_default_manifest)?;
+    let mut root = serde_json::from_str::<Root>(&current).map_err(ManifestError::Parse)?;
+    root.deployed_version = Some(version.to_string());
+    put_manifests(
+        put_fn,
+        get_fn,
+        app_name,
+        wadm_manifest,
+        wadm_default_manifest,
+        &root,
+    )
+}
+
+/// Writes TypeScript bindings for the manifest types to `ts-rs`'s default
+/// output directory (`bindings/`), so web tooling can stay in sync with the
+/// Rust core without hand-maintaining matching interfaces.
+#[cfg(feature = "ts")]
+pub fn export_bindings() -> Result<(), ts_rs::ExportError> {
+    use ts_rs::TS;
+
+    Root::export()?;
+    Manifest::export()?;
+    Metadata::export()?;
+    Annotations::export()?;
+    Spec::export()?;
+    CapabilityComponent::export()?;
+    Properties::export()?;
+    Config::export()?;
+    ConfigProperties::export()?;
+    Ok(())
+}
+
+#[cfg(all(test, feature = "ts"))]
+mod ts_bindings {
+    use super::export_bindings;
+
+    #[test]
+    fn test_export_bindings() {
+        export_bindings().expect("failed to export TypeScript bindings");
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
     use std::{fs::File, io::Read as _};
 
     use super::*;
 
     // A mock function to simulate the behavior of the key-value store `get` function
-    fn mock_get(bucket: &str, key: &str) -> Result<Option<Vec<u8>>, Box<dyn Error>> {
+    fn mock_get(bucket: &str, key: &str) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync>> {
         match (bucket, key) {
             // Simulate the retrieval of the list of application names
             ("wadm_manifests", "default") => Ok(Some(Vec::from(r#"["mds", "another-app"]"#))),
@@ -211,6 +447,150 @@ mod tests {
         }
     }
 
+    // `GetFn`/`PutFn` are plain function pointers, so round-trip tests back
+    // them with a thread-local in-memory map rather than a capturing closure.
+    thread_local! {
+        static MOCK_STORE: RefCell<HashMap<(String, String), Vec<u8>>> = RefCell::new(HashMap::new());
+    }
+
+    fn mock_store_get(bucket: &str, key: &str) -> Result<Option<Vec<u8>>, Box<dyn Error + Send + Sync>> {
+        MOCK_STORE.with(|store| {
+            Ok(store
+                .borrow()
+                .get(&(bucket.to_string(), key.to_string()))
+                .cloned())
+        })
+    }
+
+    fn mock_store_put(bucket: &str, key: &str, value: &[u8]) -> Result<(), Box<dyn Error + Send + Sync>> {
+        MOCK_STORE.with(|store| {
+            store
+                .borrow_mut()
+                .insert((bucket.to_string(), key.to_string()), value.to_vec());
+        });
+        Ok(())
+    }
+
+    fn sample_root(deployed_version: &str) -> Root {
+        let mut versions = BTreeMap::new();
+        versions.insert(
+            Version::parse("0.0.1").unwrap(),
+            Manifest {
+                api_version: "core.oam.dev/v1beta1".to_string(),
+                kind: "Application".to_string(),
+                metadata: Metadata {
+                    name: "new-app".to_string(),
+                    annotations: Annotations {
+                        description: "test".to_string(),
+                        version: "0.0.1".to_string(),
+                    },
+                },
+                spec: Spec { components: vec![] },
+            },
+        );
+
+        Root {
+            manifests: Manifests { versions },
+            deployed_version: Some(deployed_version.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_put_manifests_round_trip() {
+        MOCK_STORE.with(|store| store.borrow_mut().clear());
+        MOCK_STORE.with(|store| {
+            store.borrow_mut().insert(
+                ("wadm_manifests".to_string(), "default".to_string()),
+                Vec::from(r#"["mds"]"#),
+            );
+        });
+
+        let root = sample_root("0.0.1");
+        put_manifests(
+            mock_store_put,
+            mock_store_get,
+            "new-app",
+            "wadm_manifests",
+            "default",
+            &root,
+        )
+        .unwrap();
+
+        let apps = mock_store_get("wadm_manifests", "default").unwrap().unwrap();
+        let apps: Vec<String> = serde_json::from_slice(&apps).unwrap();
+        assert_eq!(apps, vec!["mds".to_string(), "new-app".to_string()]);
+
+        let stored = mock_store_get("wadm_manifests", "default-new-app")
+            .unwrap()
+            .unwrap();
+        let stored_root = serde_json::from_slice::<Root>(&stored).unwrap();
+        assert_eq!(stored_root, root);
+    }
+
+    #[test]
+    fn test_put_manifests_does_not_duplicate_existing_app_name() {
+        MOCK_STORE.with(|store| store.borrow_mut().clear());
+        MOCK_STORE.with(|store| {
+            store.borrow_mut().insert(
+                ("wadm_manifests".to_string(), "default".to_string()),
+                Vec::from(r#"["new-app"]"#),
+            );
+        });
+
+        let root = sample_root("0.0.1");
+        put_manifests(
+            mock_store_put,
+            mock_store_get,
+            "new-app",
+            "wadm_manifests",
+            "default",
+            &root,
+        )
+        .unwrap();
+
+        let apps = mock_store_get("wadm_manifests", "default").unwrap().unwrap();
+        let apps: Vec<String> = serde_json::from_slice(&apps).unwrap();
+        assert_eq!(apps, vec!["new-app".to_string()]);
+    }
+
+    #[test]
+    fn test_set_deployed_version_updates_existing_manifest() {
+        MOCK_STORE.with(|store| store.borrow_mut().clear());
+        MOCK_STORE.with(|store| {
+            store.borrow_mut().insert(
+                ("wadm_manifests".to_string(), "default".to_string()),
+                Vec::from(r#"["new-app"]"#),
+            );
+        });
+
+        let root = sample_root("0.0.1");
+        put_manifests(
+            mock_store_put,
+            mock_store_get,
+            "new-app",
+            "wadm_manifests",
+            "default",
+            &root,
+        )
+        .unwrap();
+
+        set_deployed_version(
+            mock_store_put,
+            mock_store_get,
+            "new-app",
+            "wadm_manifests",
+            "default",
+            "0.2.0",
+        )
+        .unwrap();
+
+        let stored = mock_store_get("wadm_manifests", "default-new-app")
+            .unwrap()
+            .unwrap();
+        let stored_root = serde_json::from_slice::<Root>(&stored).unwrap();
+        assert_eq!(stored_root.deployed_version, Some("0.2.0".to_string()));
+    }
+
     #[test]
     fn test_get_manifests() {
         let app_name = "mds";
@@ -236,10 +616,9 @@ mod tests {
 
         // Assert that an error is returned when the app is not found
         assert!(result.is_err());
-        assert_eq!(
-            result.unwrap_err().to_string(),
-            "App non_existent_app not found"
-        );
+        let err = result.unwrap_err();
+        assert!(matches!(err, ManifestError::AppNotFound(_)));
+        assert_eq!(err.to_string(), "app \"non_existent_app\" not found");
     }
 
     #[test]
@@ -247,7 +626,7 @@ mod tests {
         let json_data = r#"
         {
             "manifests": {
-                "v0.0.1": {
+                "0.0.1": {
                     "apiVersion": "core.oam.dev/v1beta1",
                     "kind": "Application",
                     "metadata": {
@@ -354,7 +733,150 @@ mod tests {
     }
 
     #[test]
-    fn test_extract_capability_components_from_file() -> Result<(), SerdeError> {
+    fn test_extract_capability_components_accepts_single_object_config() {
+        let json_data = r#"
+        {
+            "manifests": {
+                "0.0.1": {
+                    "apiVersion": "core.oam.dev/v1beta1",
+                    "kind": "Application",
+                    "metadata": {
+                        "name": "mds",
+                        "annotations": {
+                            "description": "test",
+                            "version": "0.0.1"
+                        }
+                    },
+                    "spec": {
+                        "components": [
+                            {
+                                "name": "future-ticker-deribit-btc",
+                                "type": "capability",
+                                "properties": {
+                                    "image": "ghcr.io/jabratech/ticker-provider:0.1.0",
+                                    "config": {
+                                        "name": "future-ticker-deribit-btc",
+                                        "properties": {
+                                            "uri": "192.100.1.213:4222"
+                                        }
+                                    }
+                                }
+                            }
+                        ]
+                    }
+                }
+            },
+            "deployed_version": null
+        }
+        "#;
+
+        let result = extract_capability_components(json_data).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].properties.as_ref().unwrap().config,
+            Some(vec![Config {
+                name: "future-ticker-deribit-btc".to_string(),
+                properties: Some(ConfigProperties {
+                    uri: Some("192.100.1.213:4222".to_string()),
+                    exchange_name: None,
+                    exchange: None,
+                    currency: None,
+                    instrument_kind: None,
+                }),
+            }])
+        );
+    }
+
+    #[test]
+    fn test_root_latest_and_deployed() {
+        let mut versions = BTreeMap::new();
+        versions.insert(
+            Version::parse("0.0.1").unwrap(),
+            Manifest {
+                api_version: "core.oam.dev/v1beta1".to_string(),
+                kind: "Application".to_string(),
+                metadata: Metadata {
+                    name: "mds".to_string(),
+                    annotations: Annotations {
+                        description: "old".to_string(),
+                        version: "0.0.1".to_string(),
+                    },
+                },
+                spec: Spec { components: vec![] },
+            },
+        );
+        versions.insert(
+            Version::parse("0.2.0").unwrap(),
+            Manifest {
+                api_version: "core.oam.dev/v1beta1".to_string(),
+                kind: "Application".to_string(),
+                metadata: Metadata {
+                    name: "mds".to_string(),
+                    annotations: Annotations {
+                        description: "new".to_string(),
+                        version: "0.2.0".to_string(),
+                    },
+                },
+                spec: Spec { components: vec![] },
+            },
+        );
+
+        let root = Root {
+            manifests: Manifests { versions },
+            deployed_version: Some("0.0.1".to_string()),
+        };
+
+        assert_eq!(root.latest().unwrap().metadata.annotations.description, "new");
+        assert_eq!(
+            root.deployed().unwrap().metadata.annotations.description,
+            "old"
+        );
+    }
+
+    #[test]
+    fn test_root_deployed_missing_version_is_distinct_error() {
+        let mut versions = BTreeMap::new();
+        versions.insert(
+            Version::parse("0.0.1").unwrap(),
+            Manifest {
+                api_version: "core.oam.dev/v1beta1".to_string(),
+                kind: "Application".to_string(),
+                metadata: Metadata {
+                    name: "mds".to_string(),
+                    annotations: Annotations {
+                        description: "old".to_string(),
+                        version: "0.0.1".to_string(),
+                    },
+                },
+                spec: Spec { components: vec![] },
+            },
+        );
+
+        let root = Root {
+            manifests: Manifests { versions },
+            deployed_version: Some("9.9.9".to_string()),
+        };
+
+        assert!(matches!(
+            root.deployed(),
+            Err(ManifestError::VersionNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_root_latest_empty_manifest_map() {
+        let root = Root {
+            manifests: Manifests {
+                versions: BTreeMap::new(),
+            },
+            deployed_version: None,
+        };
+
+        assert!(matches!(root.latest(), Err(ManifestError::NoVersions)));
+    }
+
+    #[test]
+    fn test_extract_capability_components_from_file() -> Result<(), ManifestError> {
         // Specify the path to your JSON file
         let path = "../manifestor/tests/app_manifest.json";
 
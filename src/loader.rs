@@ -0,0 +1,147 @@
+//! Loads manifests from a config directory, auto-detecting the format from
+//! the file extension (`.json` via `serde_json`, `.flex.bin` via
+//! `flexbuffers`), mirroring how multi-format app-manifest stores work. This
+//! decouples the crate from the KV-store-only access path in [`crate::get_manifests`]
+//! and enables offline tooling and tests against on-disk fixtures.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::ManifestError;
+use crate::Root;
+
+/// Loads a single manifest from `path`, dispatching on its file extension.
+///
+/// # Errors
+///
+/// Returns [`ManifestError::UnknownFormat`] if `path`'s extension isn't
+/// `.json` or `.flex.bin`, [`ManifestError::Io`] if the file can't be read,
+/// and [`ManifestError::Parse`] / [`ManifestError::FlexBuffers`] if the
+/// contents don't decode as a [`Root`].
+pub fn load_manifest(path: &Path) -> Result<Root, ManifestError> {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| ManifestError::UnknownFormat(path.display().to_string()))?;
+
+    if file_name.ends_with(".flex.bin") {
+        let bytes = fs::read(path)?;
+        flexbuffers::from_slice(&bytes).map_err(|e| ManifestError::FlexBuffers(e.to_string()))
+    } else if file_name.ends_with(".json") {
+        let bytes = fs::read(path)?;
+        serde_json::from_slice(&bytes).map_err(ManifestError::Parse)
+    } else {
+        Err(ManifestError::UnknownFormat(file_name.to_string()))
+    }
+}
+
+/// Loads every manifest file in `dir`, keyed by the app name derived from
+/// each manifest's latest version's `metadata.name`.
+///
+/// Non-file entries (e.g. subdirectories) are skipped.
+pub fn load_all(dir: &Path) -> Result<BTreeMap<String, Root>, ManifestError> {
+    let mut manifests = BTreeMap::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let root = load_manifest(&path)?;
+        let name = root.latest()?.metadata.name.clone();
+        manifests.insert(name, root);
+    }
+
+    Ok(manifests)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap as Map;
+
+    use semver::Version;
+
+    use super::*;
+    use crate::Annotations;
+    use crate::Manifest;
+    use crate::Manifests;
+    use crate::Metadata;
+    use crate::Spec;
+
+    fn sample_root(name: &str) -> Root {
+        let mut versions = Map::new();
+        versions.insert(
+            Version::parse("0.0.1").unwrap(),
+            Manifest {
+                api_version: "core.oam.dev/v1beta1".to_string(),
+                kind: "Application".to_string(),
+                metadata: Metadata {
+                    name: name.to_string(),
+                    annotations: Annotations {
+                        description: "test".to_string(),
+                        version: "0.0.1".to_string(),
+                    },
+                },
+                spec: Spec { components: vec![] },
+            },
+        );
+
+        Root {
+            manifests: Manifests { versions },
+            deployed_version: None,
+        }
+    }
+
+    #[test]
+    fn test_load_manifest_rejects_unknown_extension() {
+        let dir = std::env::temp_dir().join(format!("manifestor-loader-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("manifest.yaml");
+        fs::write(&path, b"not json or flexbuffers").unwrap();
+
+        let result = load_manifest(&path);
+        assert!(matches!(result, Err(ManifestError::UnknownFormat(_))));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_manifest_roundtrips_json_and_flexbuffers() {
+        let dir = std::env::temp_dir().join(format!("manifestor-loader-test-json-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let root = sample_root("mds");
+
+        let json_path = dir.join("mds.json");
+        fs::write(&json_path, serde_json::to_vec(&root).unwrap()).unwrap();
+        assert_eq!(load_manifest(&json_path).unwrap(), root);
+
+        let flex_path = dir.join("mds.flex.bin");
+        fs::write(&flex_path, flexbuffers::to_vec(&root).unwrap()).unwrap();
+        assert_eq!(load_manifest(&flex_path).unwrap(), root);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_all_keys_by_app_name() {
+        let dir = std::env::temp_dir().join(format!("manifestor-loader-test-all-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let mds = sample_root("mds");
+        let other = sample_root("another-app");
+        fs::write(dir.join("mds.json"), serde_json::to_vec(&mds).unwrap()).unwrap();
+        fs::write(
+            dir.join("another-app.flex.bin"),
+            flexbuffers::to_vec(&other).unwrap(),
+        )
+        .unwrap();
+
+        let loaded = load_all(&dir).unwrap();
+        assert_eq!(loaded.get("mds"), Some(&mds));
+        assert_eq!(loaded.get("another-app"), Some(&other));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
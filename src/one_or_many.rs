@@ -0,0 +1,62 @@
+//! Deserialization helper for fields that manifests in the wild sometimes
+//! write as a single object and sometimes as a list.
+
+use serde::Deserialize;
+use serde::Deserializer;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> From<OneOrMany<T>> for Vec<T> {
+    fn from(one_or_many: OneOrMany<T>) -> Self {
+        match one_or_many {
+            OneOrMany::One(value) => vec![value],
+            OneOrMany::Many(values) => values,
+        }
+    }
+}
+
+/// Deserializes a field that may be written as either a single `T` or a
+/// list of `T`, normalizing both forms into `Option<Vec<T>>`.
+pub fn deserialize_one_or_many<'de, D, T>(deserializer: D) -> Result<Option<Vec<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Option::<OneOrMany<T>>::deserialize(deserializer)?.map(Vec::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::deserialize_one_or_many;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Wrapper {
+        #[serde(default, deserialize_with = "deserialize_one_or_many")]
+        values: Option<Vec<u32>>,
+    }
+
+    #[test]
+    fn test_deserializes_single_value_as_one_element_vec() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{"values": 1}"#).unwrap();
+        assert_eq!(wrapper.values, Some(vec![1]));
+    }
+
+    #[test]
+    fn test_deserializes_list_as_is() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{"values": [1, 2, 3]}"#).unwrap();
+        assert_eq!(wrapper.values, Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_deserializes_missing_field_as_none() {
+        let wrapper: Wrapper = serde_json::from_str(r#"{}"#).unwrap();
+        assert_eq!(wrapper.values, None);
+    }
+}
@@ -0,0 +1,282 @@
+//! Validation pass that cross-checks a [`Root`]'s capability configs and image
+//! references before they're handed to a runtime, collecting every problem
+//! found instead of bailing out on the first one.
+
+use std::collections::HashMap;
+
+use semver::Version;
+
+use crate::CapabilityComponent;
+use crate::ConfigProperties;
+use crate::ImageRef;
+use crate::Properties;
+use crate::Root;
+use crate::Spec;
+
+const ALLOWED_INSTRUMENT_KINDS: &[&str] = &["spot", "future", "option", "perpetual"];
+
+/// A single problem found while validating a [`Root`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The component the problem was found in, as `{version}/{name}`.
+    pub component: String,
+    /// A dotted path to the offending field, e.g. `properties.config`.
+    pub field: String,
+    /// A human-readable description of the problem.
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn new(component: impl Into<String>, field: impl Into<String>, message: impl Into<String>) -> Self {
+        Diagnostic {
+            component: component.into(),
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Validates every manifest version in `root`, returning all diagnostics
+/// found rather than failing on the first one.
+pub fn validate(root: &Root) -> Result<(), Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+    for (version, manifest) in &root.manifests.versions {
+        validate_spec(version, &manifest.spec, &mut diagnostics);
+    }
+
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diagnostics)
+    }
+}
+
+fn validate_spec(version: &Version, spec: &Spec, diagnostics: &mut Vec<Diagnostic>) {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    for component in &spec.components {
+        *seen.entry(component.name.as_str()).or_insert(0) += 1;
+    }
+
+    for component in &spec.components {
+        let name = format!("{version}/{}", component.name);
+
+        if seen[component.name.as_str()] > 1 {
+            diagnostics.push(Diagnostic::new(
+                &name,
+                "name",
+                format!("duplicate component name {:?} in spec", component.name),
+            ));
+        }
+
+        validate_component(&name, component, diagnostics);
+    }
+}
+
+fn validate_component(name: &str, component: &CapabilityComponent, diagnostics: &mut Vec<Diagnostic>) {
+    if component.component_type == "capability" {
+        let has_config = component
+            .properties
+            .as_ref()
+            .is_some_and(|p| p.config.as_ref().is_some_and(|c| !c.is_empty()));
+        if !has_config {
+            diagnostics.push(Diagnostic::new(
+                name,
+                "properties.config",
+                "capability component must have at least one config entry",
+            ));
+        }
+    }
+
+    let Some(properties) = component.properties.as_ref() else {
+        return;
+    };
+    validate_properties(name, properties, diagnostics);
+}
+
+fn validate_properties(name: &str, properties: &Properties, diagnostics: &mut Vec<Diagnostic>) {
+    if let Err(e) = ImageRef::parse(&properties.image) {
+        diagnostics.push(Diagnostic::new(name, "properties.image", e.to_string()));
+    }
+
+    for config in properties.config.iter().flatten() {
+        let Some(config_properties) = config.properties.as_ref() else {
+            continue;
+        };
+        validate_config_properties(name, config_properties, diagnostics);
+    }
+}
+
+fn validate_config_properties(
+    name: &str,
+    config_properties: &ConfigProperties,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if let Some(uri) = &config_properties.uri {
+        if !is_host_port(uri) {
+            diagnostics.push(Diagnostic::new(
+                name,
+                "properties.config[].properties.uri",
+                format!("{uri:?} is not a valid host:port"),
+            ));
+        }
+    }
+
+    if let Some(instrument_kind) = &config_properties.instrument_kind {
+        if !ALLOWED_INSTRUMENT_KINDS.contains(&instrument_kind.as_str()) {
+            diagnostics.push(Diagnostic::new(
+                name,
+                "properties.config[].properties.instrument_kind",
+                format!(
+                    "{instrument_kind:?} is not one of the allowed instrument kinds {ALLOWED_INSTRUMENT_KINDS:?}"
+                ),
+            ));
+        }
+    }
+}
+
+fn is_host_port(uri: &str) -> bool {
+    match uri.rsplit_once(':') {
+        Some((host, port)) => !host.is_empty() && port.parse::<u16>().is_ok(),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Annotations;
+    use crate::Config;
+    use crate::Manifest;
+    use crate::Manifests;
+    use crate::Metadata;
+    use std::collections::BTreeMap;
+
+    fn manifest_with_components(components: Vec<CapabilityComponent>) -> Root {
+        let mut versions = BTreeMap::new();
+        versions.insert(
+            Version::parse("0.0.1").unwrap(),
+            Manifest {
+                api_version: "core.oam.dev/v1beta1".to_string(),
+                kind: "Application".to_string(),
+                metadata: Metadata {
+                    name: "mds".to_string(),
+                    annotations: Annotations {
+                        description: "test".to_string(),
+                        version: "0.0.1".to_string(),
+                    },
+                },
+                spec: Spec { components },
+            },
+        );
+        Root {
+            manifests: Manifests { versions },
+            deployed_version: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_reports_duplicate_component_names() {
+        let component = CapabilityComponent {
+            name: "dup".to_string(),
+            component_type: "component".to_string(),
+            properties: Some(Properties {
+                image: "ghcr.io/jabratech/ticker-provider:0.1.0".to_string(),
+                config: None,
+            }),
+        };
+        let root = manifest_with_components(vec![component.clone(), component]);
+
+        let diagnostics = validate(&root).unwrap_err();
+        assert!(diagnostics.iter().any(|d| d.field == "name"));
+    }
+
+    #[test]
+    fn test_validate_reports_missing_capability_config() {
+        let component = CapabilityComponent {
+            name: "cap".to_string(),
+            component_type: "capability".to_string(),
+            properties: Some(Properties {
+                image: "ghcr.io/jabratech/ticker-provider:0.1.0".to_string(),
+                config: None,
+            }),
+        };
+        let root = manifest_with_components(vec![component]);
+
+        let diagnostics = validate(&root).unwrap_err();
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.field == "properties.config"));
+    }
+
+    #[test]
+    fn test_validate_reports_bad_uri_and_instrument_kind() {
+        let component = CapabilityComponent {
+            name: "cap".to_string(),
+            component_type: "capability".to_string(),
+            properties: Some(Properties {
+                image: "ghcr.io/jabratech/ticker-provider:0.1.0".to_string(),
+                config: Some(vec![Config {
+                    name: "cap".to_string(),
+                    properties: Some(ConfigProperties {
+                        uri: Some("not-a-host-port".to_string()),
+                        exchange_name: None,
+                        exchange: None,
+                        currency: None,
+                        instrument_kind: Some("stock".to_string()),
+                    }),
+                }]),
+            }),
+        };
+        let root = manifest_with_components(vec![component]);
+
+        let diagnostics = validate(&root).unwrap_err();
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.field.ends_with("properties.uri")));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.field.ends_with("properties.instrument_kind")));
+    }
+
+    #[test]
+    fn test_validate_reports_invalid_image_reference() {
+        let component = CapabilityComponent {
+            name: "cap".to_string(),
+            component_type: "component".to_string(),
+            properties: Some(Properties {
+                image: "not an image".to_string(),
+                config: None,
+            }),
+        };
+        let root = manifest_with_components(vec![component]);
+
+        let diagnostics = validate(&root).unwrap_err();
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.field == "properties.image"));
+    }
+
+    #[test]
+    fn test_validate_passes_for_well_formed_manifest() {
+        let component = CapabilityComponent {
+            name: "cap".to_string(),
+            component_type: "capability".to_string(),
+            properties: Some(Properties {
+                image: "ghcr.io/jabratech/ticker-provider:0.1.0@sha256:aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa".to_string(),
+                config: Some(vec![Config {
+                    name: "cap".to_string(),
+                    properties: Some(ConfigProperties {
+                        uri: Some("192.100.1.213:4222".to_string()),
+                        exchange_name: None,
+                        exchange: None,
+                        currency: None,
+                        instrument_kind: Some("future".to_string()),
+                    }),
+                }]),
+            }),
+        };
+        let root = manifest_with_components(vec![component]);
+
+        assert_eq!(validate(&root), Ok(()));
+    }
+}